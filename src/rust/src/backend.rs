@@ -0,0 +1,19 @@
+//! Compile-time selection of the curve/field backend the FFI layer
+//! operates over, picked via the mutually exclusive `pallas` and `vesta`
+//! Cargo features (see the crate's `Cargo.toml`). `FFIScalar`,
+//! `FFIPoint`, and every arithmetic entry point are written against the
+//! aliases here rather than against `pasta_curves::{pallas, vesta}`
+//! directly, so one crate serves both base-field and scalar-field
+//! callers without forking the FFI signatures.
+
+#[cfg(all(feature = "pallas", feature = "vesta"))]
+compile_error!("features \"pallas\" and \"vesta\" are mutually exclusive; enable exactly one");
+
+#[cfg(not(any(feature = "pallas", feature = "vesta")))]
+compile_error!("select a backend: enable either the \"pallas\" or the \"vesta\" feature");
+
+#[cfg(feature = "pallas")]
+pub use pasta_curves::{pallas::Affine as CurveAffine, pallas::Point as Curve, Fq as Scalar};
+
+#[cfg(feature = "vesta")]
+pub use pasta_curves::{vesta::Affine as CurveAffine, vesta::Point as Curve, Fp as Scalar};
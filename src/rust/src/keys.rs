@@ -0,0 +1,209 @@
+//! Orchard-style wallet key-derivation primitives over the selected
+//! backend curve, mirroring the helper set `librustzcash` exposes
+//! (`to_scalar`, `ask_to_ak`, `nsk_to_nk`, `crh_ivk`).
+
+use pasta_curves::arithmetic::CurveExt;
+use pasta_curves::group::ff::{Field, FromUniformBytes};
+
+use crate::backend::{Curve, Scalar};
+use crate::point::{ffi_to_point, FFIPoint};
+use crate::{ffi_to_field_element, FFIScalar};
+
+/// Hashes `domain` and `msg` to a backend curve point, used to derive the
+/// fixed generators below independently of each other and of the curve's
+/// standard generator.
+fn group_hash(domain: &str, msg: &[u8]) -> Curve {
+    let hasher = Curve::hash_to_curve(domain);
+    hasher(msg)
+}
+
+/// Fixed generator for spend-authorization keys (`ak = [ask] * G`).
+fn spend_auth_generator() -> Curve {
+    group_hash("z.cash:Orchard-SpendAuthG", &[])
+}
+
+/// Fixed generator for nullifier keys (`nk = [nsk] * G'`), independent of
+/// [`spend_auth_generator`].
+fn nullifier_k_generator() -> Curve {
+    group_hash("z.cash:Orchard-NullifierK", &[])
+}
+
+/// Reduces a 64-byte blob to a `Scalar` element by treating it as a wide
+/// little-endian integer and reducing modulo the scalar field, the same
+/// construction `from_uniform_bytes` performs for Sapling/Orchard's
+/// `to_scalar`.
+///
+/// # Safety
+///
+/// `input64` must be valid for reads of 64 bytes and `out` valid for
+/// writes of an `FFIScalar`; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_to_scalar(input64: *const u8, out: *mut FFIScalar) -> i32 {
+    let bytes = unsafe { std::slice::from_raw_parts(input64, 64) };
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(bytes);
+
+    let scalar = Scalar::from_uniform_bytes(&wide);
+
+    let out = unsafe { &mut *out };
+    *out = FFIScalar::from_field_element(scalar);
+    0
+}
+
+/// Derives a spend-authorizing key `ak = [ask] * G` from a spend
+/// authorization private key `ask`.
+///
+/// # Safety
+///
+/// `ask` must be valid for reads and `out` valid for writes of their
+/// respective types; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_ask_to_ak(ask: *const FFIScalar, out: *mut FFIPoint) -> i32 {
+    let ask = match ffi_to_field_element(ask) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let out = unsafe { &mut *out };
+    *out = FFIPoint::from_point(spend_auth_generator() * ask);
+    0
+}
+
+/// Derives a nullifier key `nk = [nsk] * G'` from a nullifier private key
+/// `nsk`, using a generator independent of [`librustmonero_ask_to_ak`]'s.
+///
+/// # Safety
+///
+/// `nsk` must be valid for reads and `out` valid for writes of their
+/// respective types; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_nsk_to_nk(nsk: *const FFIScalar, out: *mut FFIPoint) -> i32 {
+    let nsk = match ffi_to_field_element(nsk) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let out = unsafe { &mut *out };
+    *out = FFIPoint::from_point(nullifier_k_generator() * nsk);
+    0
+}
+
+/// Derives an incoming viewing key by hashing the compressed `ak`/`nk`
+/// encodings with a fixed personalization and reducing the digest into
+/// `Scalar`. Returns a nonzero status if either point fails to decode, or if
+/// the derived key is zero, which must be rejected.
+///
+/// # Safety
+///
+/// `ak` and `nk` must be valid for reads and `out` valid for writes of
+/// their respective types; all three must be non-null and properly
+/// aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_crh_ivk(
+    ak: *const FFIPoint,
+    nk: *const FFIPoint,
+    out: *mut FFIScalar,
+) -> i32 {
+    let ak = match ffi_to_point(ak) {
+        Some(p) => p,
+        None => return 1,
+    };
+    let nk = match ffi_to_point(nk) {
+        Some(p) => p,
+        None => return 1,
+    };
+
+    let ak_bytes = FFIPoint::from_point(ak);
+    let nk_bytes = FFIPoint::from_point(nk);
+
+    let digest = blake2b_simd::Params::new()
+        .hash_length(64)
+        .personal(b"Rust_MoneroCRHIv")
+        .to_state()
+        .update(ak_bytes.as_bytes().as_slice())
+        .update(nk_bytes.as_bytes().as_slice())
+        .finalize();
+
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(digest.as_bytes());
+    let ivk = Scalar::from_uniform_bytes(&wide);
+
+    if bool::from(ivk.is_zero()) {
+        return 1;
+    }
+
+    let out = unsafe { &mut *out };
+    *out = FFIScalar::from_field_element(ivk);
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pasta_curves::group::Group;
+
+    fn decode(s: &FFIScalar) -> Scalar {
+        ffi_to_field_element(s).expect("test value must decode")
+    }
+
+    #[test]
+    fn ask_to_ak_and_nsk_to_nk_use_independent_generators() {
+        let scalar = FFIScalar::from_field_element(Scalar::from(9u64));
+        let mut ak = FFIPoint::from_point(Curve::identity());
+        let mut nk = FFIPoint::from_point(Curve::identity());
+
+        assert_eq!(unsafe { librustmonero_ask_to_ak(&scalar, &mut ak) }, 0);
+        assert_eq!(unsafe { librustmonero_nsk_to_nk(&scalar, &mut nk) }, 0);
+
+        assert_ne!(ak.as_bytes(), nk.as_bytes());
+    }
+
+    #[test]
+    fn to_scalar_reduces_the_full_64_byte_input() {
+        // Agree on the low 32 bytes, differ only in the high half, so a
+        // reduction that ignored the upper 32 bytes would (incorrectly)
+        // produce the same scalar for both.
+        let mut low_half_only = [0u8; 64];
+        low_half_only[0] = 7;
+        let mut full_width = low_half_only;
+        full_width[63] = 1;
+
+        let mut scalar_a = FFIScalar::from_field_element(Scalar::ZERO);
+        let mut scalar_b = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(
+            unsafe { librustmonero_to_scalar(low_half_only.as_ptr(), &mut scalar_a) },
+            0
+        );
+        assert_eq!(
+            unsafe { librustmonero_to_scalar(full_width.as_ptr(), &mut scalar_b) },
+            0
+        );
+
+        assert_ne!(decode(&scalar_a), decode(&scalar_b));
+    }
+
+    #[test]
+    fn crh_ivk_is_deterministic_and_matches_the_blake2b_construction() {
+        let ak = FFIPoint::from_point(Curve::generator());
+        let nk = FFIPoint::from_point(Curve::generator() * Scalar::from(2u64));
+
+        let mut first = FFIScalar::from_field_element(Scalar::ZERO);
+        let mut second = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_crh_ivk(&ak, &nk, &mut first) }, 0);
+        assert_eq!(unsafe { librustmonero_crh_ivk(&ak, &nk, &mut second) }, 0);
+        assert_eq!(decode(&first), decode(&second), "must be deterministic");
+
+        let digest = blake2b_simd::Params::new()
+            .hash_length(64)
+            .personal(b"Rust_MoneroCRHIv")
+            .to_state()
+            .update(ak.as_bytes().as_slice())
+            .update(nk.as_bytes().as_slice())
+            .finalize();
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(digest.as_bytes());
+        let expected = Scalar::from_uniform_bytes(&wide);
+
+        assert_eq!(decode(&first), expected);
+    }
+}
@@ -1,35 +1,330 @@
-use pasta_curves::{group::ff::PrimeFieldBits, Fq};
+use pasta_curves::group::ff::{Field, PrimeField};
 
+mod backend;
+mod keys;
+mod point;
+
+use backend::Scalar;
+pub use keys::{
+    librustmonero_ask_to_ak, librustmonero_crh_ivk, librustmonero_nsk_to_nk,
+    librustmonero_to_scalar,
+};
+pub use point::{FFIPoint, librustmonero_msm, librustmonero_point_add, librustmonero_point_mul};
+
+/// A little-endian, non-Montgomery representation of a [`Scalar`], as
+/// exchanged with C callers. Decoding always goes through [`Scalar::from_repr`]
+/// so non-canonical (>= modulus) encodings are rejected instead of being
+/// silently accepted, which is what reinterpreting the limbs as a native
+/// `Scalar` layout used to do.
+///
+/// The representation is a fixed `[u8; 32]` rather than `[u64; 4]` so the
+/// struct's layout does not depend on pointer width: `Scalar`'s
+/// `PrimeFieldBits::ReprBits` is `[u64; 4]` on 64-bit targets but
+/// `[u32; 8]` on 32-bit ones, and `FFIScalar` needs to stay a stable
+/// 32-byte C ABI type everywhere.
 #[derive(Clone, Copy)]
-#[repr(C, packed)]
-pub struct FFIScalar([u64; 4]);
+#[repr(C)]
+pub struct FFIScalar([u8; 32]);
 
 impl FFIScalar {
-    pub fn from_field_element(element: Fq) -> Self {
-        Self(element.to_le_bits().into_inner())
+    pub fn from_field_element(element: Scalar) -> Self {
+        Self(element.to_repr())
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
     }
 }
 
+/// Computes `result = s1 * s2`.
+///
+/// # Safety
+///
+/// `s1` and `s2` must be valid for reads and `result` valid for writes of
+/// an `FFIScalar`; all three must be non-null and properly aligned.
 #[no_mangle]
-pub extern "C" fn librustmonero_mul(s1: *const FFIScalar, s2: *const FFIScalar, result: *mut FFIScalar) {
-    let scalar1 = ffi_to_field_element(s1);
-    let scalar2 = ffi_to_field_element(s2);
+pub unsafe extern "C" fn librustmonero_mul(
+    s1: *const FFIScalar,
+    s2: *const FFIScalar,
+    result: *mut FFIScalar,
+) -> i32 {
+    let scalar1 = match ffi_to_field_element(s1) {
+        Some(s) => s,
+        None => return 1,
+    };
+    let scalar2 = match ffi_to_field_element(s2) {
+        Some(s) => s,
+        None => return 1,
+    };
 
     let mul = scalar1 * scalar2;
 
     let result = unsafe { &mut *result };
     *result = FFIScalar::from_field_element(mul);
+    0
+}
+
+/// Computes `result = s1 + s2`.
+///
+/// # Safety
+///
+/// `s1` and `s2` must be valid for reads and `result` valid for writes of
+/// an `FFIScalar`; all three must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_add(
+    s1: *const FFIScalar,
+    s2: *const FFIScalar,
+    result: *mut FFIScalar,
+) -> i32 {
+    let scalar1 = match ffi_to_field_element(s1) {
+        Some(s) => s,
+        None => return 1,
+    };
+    let scalar2 = match ffi_to_field_element(s2) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(scalar1 + scalar2);
+    0
+}
+
+/// Computes `result = s1 - s2`.
+///
+/// # Safety
+///
+/// `s1` and `s2` must be valid for reads and `result` valid for writes of
+/// an `FFIScalar`; all three must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_sub(
+    s1: *const FFIScalar,
+    s2: *const FFIScalar,
+    result: *mut FFIScalar,
+) -> i32 {
+    let scalar1 = match ffi_to_field_element(s1) {
+        Some(s) => s,
+        None => return 1,
+    };
+    let scalar2 = match ffi_to_field_element(s2) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(scalar1 - scalar2);
+    0
+}
+
+/// Computes `result = -s`.
+///
+/// # Safety
+///
+/// `s` must be valid for reads and `result` valid for writes of an
+/// `FFIScalar`; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_neg(s: *const FFIScalar, result: *mut FFIScalar) -> i32 {
+    let scalar = match ffi_to_field_element(s) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(-scalar);
+    0
+}
+
+/// Computes `result = s * s`.
+///
+/// # Safety
+///
+/// `s` must be valid for reads and `result` valid for writes of an
+/// `FFIScalar`; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_square(s: *const FFIScalar, result: *mut FFIScalar) -> i32 {
+    let scalar = match ffi_to_field_element(s) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(scalar.square());
+    0
+}
+
+/// Inverts `s`. Returns `1` if `s` failed to decode or was zero (no
+/// inverse exists), leaving `result` untouched in that case. `Scalar::invert`
+/// itself runs without secret-dependent branching, so the field inversion
+/// does not introduce a timing side-channel of its own; the status code
+/// above already tells the caller whether the input was zero, so there is
+/// no secrecy of that fact left to protect at this layer.
+///
+/// # Safety
+///
+/// `s` must be valid for reads and `result` valid for writes of an
+/// `FFIScalar`; both must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_invert(s: *const FFIScalar, result: *mut FFIScalar) -> i32 {
+    let scalar = match ffi_to_field_element(s) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let inverted = scalar.invert();
+    if inverted.is_none().into() {
+        return 1;
+    }
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(inverted.unwrap());
+    0
+}
+
+/// Raises `s` to the power given by `exp`, a little-endian `u64` exponent.
+///
+/// # Safety
+///
+/// `s` must be valid for reads and `result` valid for writes of an
+/// `FFIScalar`; `exp` must be valid for reads of `exp_len` consecutive
+/// `u64`s; all three must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_pow(
+    s: *const FFIScalar,
+    exp: *const u64,
+    exp_len: usize,
+    result: *mut FFIScalar,
+) -> i32 {
+    let scalar = match ffi_to_field_element(s) {
+        Some(s) => s,
+        None => return 1,
+    };
+
+    let exp = unsafe { std::slice::from_raw_parts(exp, exp_len) };
+
+    let result = unsafe { &mut *result };
+    *result = FFIScalar::from_field_element(scalar.pow(exp));
+    0
+}
+
+/// Decodes an `FFIScalar` into a canonical `Scalar`, rejecting any encoding
+/// that is not in `[0, p)`. Returns `None` on non-canonical input instead
+/// of the previous `transmute`-based path, which assumed `Scalar`'s in-memory
+/// layout matched raw little-endian limbs and invoked undefined behavior.
+pub(crate) fn ffi_to_field_element(scalar: *const FFIScalar) -> Option<Scalar> {
+    let scalar = unsafe { scalar.as_ref().expect("Big trouble") };
+    let repr = <Scalar as PrimeField>::Repr::from(*scalar.as_bytes());
+    Option::from(Scalar::from_repr(repr))
 }
 
-fn ffi_to_field_element(scalar: *const FFIScalar) -> Fq {
-    unsafe {
-        let bytes = scalar.as_ref().expect("Big trouble");
-        std::mem::transmute::<FFIScalar, Fq>(*bytes)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Increments a little-endian byte array by one, as a big integer.
+    fn le_bytes_plus_one(bytes: [u8; 32]) -> [u8; 32] {
+        let mut out = bytes;
+        for byte in out.iter_mut() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                break;
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn decode_accepts_p_minus_one_and_rejects_p_and_above() {
+        let p_minus_one: [u8; 32] = (-Scalar::ONE).to_repr();
+        assert!(ffi_to_field_element(&FFIScalar(p_minus_one)).is_some());
+
+        let p: [u8; 32] = le_bytes_plus_one(p_minus_one);
+        assert!(ffi_to_field_element(&FFIScalar(p)).is_none());
+
+        let p_plus_one: [u8; 32] = le_bytes_plus_one(p);
+        assert!(ffi_to_field_element(&FFIScalar(p_plus_one)).is_none());
+
+        let max = [0xffu8; 32];
+        assert!(ffi_to_field_element(&FFIScalar(max)).is_none());
+    }
+
+    fn decode(s: &FFIScalar) -> Scalar {
+        ffi_to_field_element(s).expect("test value must decode")
+    }
+
+    #[test]
+    fn add_matches_field_addition() {
+        let a = Scalar::from(7u64);
+        let b = Scalar::from(11u64);
+        let (fa, fb) = (FFIScalar::from_field_element(a), FFIScalar::from_field_element(b));
+        let mut out = FFIScalar::from_field_element(Scalar::ZERO);
+
+        assert_eq!(unsafe { librustmonero_add(&fa, &fb, &mut out) }, 0);
+        assert_eq!(decode(&out), a + b);
+    }
+
+    #[test]
+    fn sub_and_neg_round_trip() {
+        let a = Scalar::from(7u64);
+        let b = Scalar::from(11u64);
+        let (fa, fb) = (FFIScalar::from_field_element(a), FFIScalar::from_field_element(b));
+
+        let mut diff = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_sub(&fa, &fb, &mut diff) }, 0);
+        assert_eq!(decode(&diff), a - b);
+
+        let mut negated = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_neg(&diff, &mut negated) }, 0);
+        assert_eq!(decode(&negated), b - a);
+    }
+
+    #[test]
+    fn square_matches_mul_with_itself() {
+        let s = FFIScalar::from_field_element(Scalar::from(13u64));
+
+        let mut squared = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_square(&s, &mut squared) }, 0);
+
+        let mut multiplied = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_mul(&s, &s, &mut multiplied) }, 0);
+
+        assert_eq!(decode(&squared), decode(&multiplied));
+    }
+
+    #[test]
+    fn invert_rejects_zero_and_otherwise_round_trips_to_one() {
+        let zero = FFIScalar::from_field_element(Scalar::ZERO);
+        let mut result = FFIScalar::from_field_element(Scalar::ONE);
+
+        assert_eq!(unsafe { librustmonero_invert(&zero, &mut result) }, 1);
+        assert_eq!(decode(&result), Scalar::ONE, "result must be left untouched");
+
+        let s = FFIScalar::from_field_element(Scalar::from(17u64));
+        let mut inverted = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_invert(&s, &mut inverted) }, 0);
+
+        let mut product = FFIScalar::from_field_element(Scalar::ZERO);
+        assert_eq!(unsafe { librustmonero_mul(&s, &inverted, &mut product) }, 0);
+        assert_eq!(decode(&product), Scalar::ONE);
+    }
+
+    #[test]
+    fn pow_matches_scalar_pow_for_a_multi_limb_exponent() {
+        let s = Scalar::from(3u64);
+        let fs = FFIScalar::from_field_element(s);
+        // 2^64 + 1, spanning two `u64` limbs.
+        let exp: [u64; 2] = [1, 1];
+        let mut out = FFIScalar::from_field_element(Scalar::ZERO);
+
+        let status = unsafe { librustmonero_pow(&fs, exp.as_ptr(), exp.len(), &mut out) };
+
+        assert_eq!(status, 0);
+        assert_eq!(decode(&out), s.pow(exp));
     }
 }
 
 // #[no_mangle]
-// pub extern "C" fn librustmonero_display(scalar: *const FFIScalar) {
+// pub unsafe extern "C" fn librustmonero_display(scalar: *const FFIScalar) {
 //     let scalar = unsafe {
 //         let scalar = Scalar::from_raw(*scalar);
 //         scalar
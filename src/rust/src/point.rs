@@ -0,0 +1,237 @@
+//! FFI surface for the selected backend's group elements, including a batched
+//! multi-scalar multiplication entry point.
+
+use pasta_curves::group::{ff::PrimeField, Curve as _, Group, GroupEncoding};
+
+use crate::backend::{Curve, CurveAffine};
+use crate::{ffi_to_field_element, FFIScalar};
+
+/// A compressed, canonical encoding of a backend group element, as
+/// exchanged with C callers.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct FFIPoint([u8; 32]);
+
+impl FFIPoint {
+    pub fn from_point(point: Curve) -> Self {
+        Self(point.to_affine().to_bytes())
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+pub(crate) fn ffi_to_point(point: *const FFIPoint) -> Option<Curve> {
+    let point = unsafe { point.as_ref().expect("Big trouble") };
+    let affine: Option<CurveAffine> = Option::from(CurveAffine::from_bytes(&point.0));
+    affine.map(Curve::from)
+}
+
+/// Computes `result = scalar * point`.
+///
+/// # Safety
+///
+/// `scalar` and `point` must be valid for reads and `result` valid for
+/// writes of their respective types; all three must be non-null and
+/// properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_point_mul(
+    scalar: *const FFIScalar,
+    point: *const FFIPoint,
+    result: *mut FFIPoint,
+) -> i32 {
+    let scalar = match ffi_to_field_element(scalar) {
+        Some(s) => s,
+        None => return 1,
+    };
+    let point = match ffi_to_point(point) {
+        Some(p) => p,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIPoint::from_point(point * scalar);
+    0
+}
+
+/// Computes `result = p1 + p2`.
+///
+/// # Safety
+///
+/// `p1` and `p2` must be valid for reads and `result` valid for writes of
+/// an `FFIPoint`; all three must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_point_add(
+    p1: *const FFIPoint,
+    p2: *const FFIPoint,
+    result: *mut FFIPoint,
+) -> i32 {
+    let p1 = match ffi_to_point(p1) {
+        Some(p) => p,
+        None => return 1,
+    };
+    let p2 = match ffi_to_point(p2) {
+        Some(p) => p,
+        None => return 1,
+    };
+
+    let result = unsafe { &mut *result };
+    *result = FFIPoint::from_point(p1 + p2);
+    0
+}
+
+/// Batched multi-scalar multiplication using Pippenger's bucket method.
+///
+/// Computes `sum(scalars[i] * points[i])` for `i in 0..len`. Returns a
+/// nonzero status if any scalar or point fails to decode.
+///
+/// # Safety
+///
+/// `scalars` and `points` must each be valid for reads of `len` consecutive
+/// elements of their respective types, and `out` valid for writes of an
+/// `FFIPoint`; all three must be non-null and properly aligned.
+#[no_mangle]
+pub unsafe extern "C" fn librustmonero_msm(
+    scalars: *const FFIScalar,
+    points: *const FFIPoint,
+    len: usize,
+    out: *mut FFIPoint,
+) -> i32 {
+    assert!(!scalars.is_null(), "Big trouble");
+    assert!(!points.is_null(), "Big trouble");
+    assert!(!out.is_null(), "Big trouble");
+
+    let scalars = unsafe { std::slice::from_raw_parts(scalars, len) };
+    let points = unsafe { std::slice::from_raw_parts(points, len) };
+
+    let mut decoded_scalars = Vec::with_capacity(len);
+    for scalar in scalars {
+        match ffi_to_field_element(scalar) {
+            Some(s) => decoded_scalars.push(s.to_repr()),
+            None => return 1,
+        }
+    }
+
+    let mut decoded_points = Vec::with_capacity(len);
+    for point in points {
+        match ffi_to_point(point) {
+            Some(p) => decoded_points.push(p),
+            None => return 1,
+        }
+    }
+
+    let result = msm(&decoded_scalars, &decoded_points);
+
+    let out = unsafe { &mut *out };
+    *out = FFIPoint::from_point(result);
+    0
+}
+
+/// Chooses a Pippenger window width in bits for `len` scalar-point pairs:
+/// roughly `ln(len)`, clamped to a sane range so tiny inputs still get a
+/// window of at least 1 bit and huge inputs don't blow past ~15 bits
+/// (2^15 - 1 buckets).
+fn window_width(len: usize) -> usize {
+    if len < 4 {
+        1
+    } else {
+        ((len as f64).ln().ceil() as usize).clamp(1, 15)
+    }
+}
+
+/// Extracts a `width`-bit little-endian window starting at bit `start`
+/// from a canonical 32-byte scalar representation.
+fn window_bits(repr: &[u8], start: usize, width: usize) -> usize {
+    let mut value = 0usize;
+    for i in 0..width {
+        let bit_pos = start + i;
+        if bit_pos >= repr.len() * 8 {
+            break;
+        }
+        let bit = (repr[bit_pos / 8] >> (bit_pos % 8)) & 1;
+        value |= (bit as usize) << i;
+    }
+    value
+}
+
+/// Pippenger's bucket method: split each scalar into windows of `c` bits,
+/// accumulate points into `2^c - 1` buckets per window, reduce each
+/// window with the running-sum trick, then fold windows together by
+/// doubling the accumulator `c` times between windows.
+fn msm<R: AsRef<[u8]>>(scalars: &[R], points: &[Curve]) -> Curve {
+    let len = scalars.len();
+    if len == 0 {
+        return Curve::identity();
+    }
+
+    let c = window_width(len);
+    let num_windows = 256usize.div_ceil(c);
+
+    let mut acc = Curve::identity();
+    for w in (0..num_windows).rev() {
+        if w != num_windows - 1 {
+            for _ in 0..c {
+                acc = acc.double();
+            }
+        }
+
+        let num_buckets = (1usize << c) - 1;
+        let mut buckets = vec![Curve::identity(); num_buckets];
+        for (scalar, point) in scalars.iter().zip(points.iter()) {
+            let bucket = window_bits(scalar.as_ref(), w * c, c);
+            if bucket != 0 {
+                buckets[bucket - 1] += point;
+            }
+        }
+
+        let mut running = Curve::identity();
+        let mut window_sum = Curve::identity();
+        for bucket in buckets.into_iter().rev() {
+            running += bucket;
+            window_sum += running;
+        }
+
+        acc += window_sum;
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Scalar;
+
+    #[test]
+    fn msm_matches_naive_sum() {
+        let scalars: Vec<Scalar> = (1..=20u64).map(Scalar::from).collect();
+        let points: Vec<Curve> = (1..=20u64)
+            .map(|i| Curve::generator() * Scalar::from(i))
+            .collect();
+
+        let ffi_scalars: Vec<FFIScalar> = scalars
+            .iter()
+            .map(|s| FFIScalar::from_field_element(*s))
+            .collect();
+        let ffi_points: Vec<FFIPoint> = points.iter().map(|p| FFIPoint::from_point(*p)).collect();
+
+        let mut out = FFIPoint::from_point(Curve::identity());
+        let status = unsafe {
+            librustmonero_msm(
+                ffi_scalars.as_ptr(),
+                ffi_points.as_ptr(),
+                ffi_scalars.len(),
+                &mut out,
+            )
+        };
+        assert_eq!(status, 0);
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(Curve::identity(), |acc, (s, p)| acc + *p * *s);
+
+        assert_eq!(out.as_bytes(), FFIPoint::from_point(expected).as_bytes());
+    }
+}